@@ -7,19 +7,41 @@
 //! ```
 //! use arcade_queue::Queue;
 //!
-//! let q = Queue::new("", 1).unwrap();
+//! let q: Queue<&str> = Queue::new("", 1).unwrap();
 //! ```
 
-use std::{collections::VecDeque, fmt};
+use std::{borrow::Borrow, collections::VecDeque, fmt};
 use thiserror::Error;
 
+mod lobby;
 mod macros;
 
+pub use lobby::{Lobby, LobbyError};
+
+#[derive(PartialEq, Debug, Clone)]
+struct Entry<T: PartialEq + Clone> {
+    priority: i32,
+    seq: u64,
+    player: T,
+}
+
+impl<T: PartialEq + Clone> Entry<T> {
+    /// Key used to rank entries in priority mode: highest priority first, ties broken by
+    /// earliest join order. The same key drives `nextone`'s selection and the `peek_*` preview
+    /// methods, so they always agree on what comes next.
+    fn priority_rank(&self) -> (i32, std::cmp::Reverse<u64>) {
+        (self.priority, std::cmp::Reverse(self.seq))
+    }
+}
+
 #[derive(PartialEq, Debug)]
-pub struct Queue<'a> {
-    game: &'a str,
+pub struct Queue<T: PartialEq + Clone> {
+    game: String,
     players: u8,
-    queue: VecDeque<&'a str>,
+    queue: VecDeque<Entry<T>>,
+    seq: u64,
+    priority_mode: bool,
+    max_len: Option<usize>,
 }
 
 #[derive(Error, Debug)]
@@ -28,9 +50,60 @@ pub enum QueueError {
     TooLessPlayersError,
     #[error("the player is already in the queue")]
     AlreadyInQueueError,
+    #[error("the player is not in the queue")]
+    PlayerNotFound,
+    #[error("the queue is full")]
+    QueueFull,
+}
+
+/// An owning iterator over the players in a [`Queue`], in queue order.
+///
+/// Created by the `into_iter` method on [`Queue`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T: PartialEq + Clone>(VecDeque<Entry<T>>);
+
+impl<T: PartialEq + Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front().map(|e| e.player)
+    }
+}
+
+impl<T: PartialEq + Clone> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.queue)
+    }
+}
+
+/// An iterator over the players in a [`Queue`], in queue order.
+///
+/// Created by the [`iter`](Queue::iter) method on [`Queue`], or by the `into_iter` method on
+/// `&Queue` (provided by the [`IntoIterator`] trait).
+pub struct Iter<'a, T: PartialEq + Clone> {
+    inner: std::collections::vec_deque::Iter<'a, Entry<T>>,
+}
+
+impl<'a, T: PartialEq + Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|e| &e.player)
+    }
+}
+
+impl<'a, T: PartialEq + Clone> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl<'a> fmt::Display for Queue<'a> {
+impl<T: PartialEq + Clone + fmt::Display> fmt::Display for Queue<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -42,7 +115,7 @@ impl<'a> fmt::Display for Queue<'a> {
     }
 }
 
-impl<'a> Queue<'a> {
+impl<T: PartialEq + Clone> Queue<T> {
     /// Creates an empty queue with a name to the game and a number of players for each round.
     ///
     /// `players` cannot be less than 1.
@@ -54,19 +127,131 @@ impl<'a> Queue<'a> {
     /// ```
     /// use arcade_queue::Queue;
     ///
-    /// let q = Queue::new("", 1).unwrap();
+    /// let q: Queue<&str> = Queue::new("", 1).unwrap();
     /// ```
-    pub fn new(game: &'a str, players: u8) -> Result<Self, QueueError> {
+    pub fn new(game: impl Into<String>, players: u8) -> Result<Self, QueueError> {
         if players == 0 {
             return Err(QueueError::TooLessPlayersError);
         }
         Ok(Self {
-            game,
+            game: game.into(),
             players,
             queue: VecDeque::new(),
+            seq: 0,
+            priority_mode: false,
+            max_len: None,
         })
     }
 
+    /// Creates an empty queue with room for `cap` players reserved up front, and capped at
+    /// `cap` players: once the line is at capacity, `join` returns [`QueueError::QueueFull`]
+    /// until someone leaves. Use [`set_max_len`](Queue::set_max_len) to change or lift the cap.
+    ///
+    /// `players` cannot be less than 1.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::with_capacity("", 1, 1).unwrap();
+    ///
+    /// q.join("player1").unwrap();
+    /// assert!(q.join("player2").is_err());
+    /// ```
+    pub fn with_capacity(
+        game: impl Into<String>,
+        players: u8,
+        cap: usize,
+    ) -> Result<Self, QueueError> {
+        let mut queue = Self::new(game, players)?;
+        queue.queue = VecDeque::with_capacity(cap);
+        queue.max_len = Some(cap);
+        Ok(queue)
+    }
+
+    /// Sets or lifts the maximum number of players this queue will hold. `join` returns
+    /// [`QueueError::QueueFull`] once the line is at the maximum.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    /// q.set_max_len(Some(1));
+    ///
+    /// q.join("player1").unwrap();
+    /// assert!(q.join("player2").is_err());
+    ///
+    /// q.set_max_len(None);
+    /// q.join("player2").unwrap();
+    /// ```
+    #[inline]
+    pub fn set_max_len(&mut self, max_len: Option<usize>) {
+        self.max_len = max_len;
+    }
+
+    /// Returns the number of players currently waiting in the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    /// q.join("player1");
+    ///
+    /// assert_eq!(q.len(), 1);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if no players are waiting in the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    /// assert!(q.is_empty());
+    ///
+    /// q.join("player1");
+    /// assert!(!q.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Returns the number of players the queue can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let q = Queue::<&str>::with_capacity("", 1, 12).unwrap();
+    /// assert_eq!(q.capacity(), 12);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
     /// Adds a player to the end of the queue. The player should not be in the queue already.
     ///
     /// # Examples
@@ -84,13 +269,49 @@ impl<'a> Queue<'a> {
     /// assert_eq!(q.format_queue(), "player1, player2");
     /// ```
     #[inline]
-    pub fn join(&mut self, player: &'a str) -> Result<(), QueueError> {
-        if !self.queue.contains(&player) {
-            self.queue.push_back(player);
-            Ok(())
-        } else {
-            Err(QueueError::AlreadyInQueueError)
+    pub fn join(&mut self, player: T) -> Result<(), QueueError> {
+        self.join_with_priority(player, 0)
+    }
+
+    /// Adds a player to the queue with a priority, jumping ahead of lower-priority players.
+    /// The player should not be in the queue already.
+    ///
+    /// Joining with a nonzero priority at least once opts the queue into priority mode: `nextone` and `next_group`
+    /// then dequeue the highest-priority player still waiting (ties broken by join order)
+    /// instead of just the front of the line, which makes them O(n) instead of O(1).
+    /// `join` is equivalent to joining with priority `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join_with_priority("player2", 1);
+    ///
+    /// assert_eq!(q.nextone().unwrap(), "player2");
+    /// ```
+    pub fn join_with_priority(&mut self, player: T, priority: i32) -> Result<(), QueueError> {
+        if self.queue.iter().any(|e| e.player == player) {
+            return Err(QueueError::AlreadyInQueueError);
         }
+        if self.max_len.is_some_and(|max| self.queue.len() >= max) {
+            return Err(QueueError::QueueFull);
+        }
+        if priority != 0 {
+            self.priority_mode = true;
+        }
+        self.queue.push_back(Entry {
+            priority,
+            seq: self.seq,
+            player,
+        });
+        self.seq += 1;
+        Ok(())
     }
 
     /// Remove a player from the queue.
@@ -110,12 +331,170 @@ impl<'a> Queue<'a> {
     /// assert_eq!(q.format_queue(), "player1, player2");
     /// ```
     #[inline]
-    pub fn quit(&mut self, player: &'a str) {
-        self.queue.retain(|p| *p != player);
+    pub fn quit<Q>(&mut self, player: &Q)
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.queue.retain(|e| e.player.borrow() != player);
+    }
+
+    /// Returns the position of a player in the queue, or `None` if they are not waiting.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    ///
+    /// assert_eq!(q.position("player2"), Some(1));
+    /// assert_eq!(q.position("player3"), None);
+    /// ```
+    pub fn position<Q>(&self, player: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.queue.iter().position(|e| e.player.borrow() == player)
+    }
+
+    /// Moves a player to a specific index in the queue, shifting the others over.
+    ///
+    /// `index` is clamped to the current length of the queue, so moving to an
+    /// out-of-bounds index moves the player to the back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    /// q.join("player3");
+    ///
+    /// q.move_to("player3", 0).unwrap();
+    /// assert_eq!(q.get_queue(), vec!["player3", "player1", "player2"]);
+    /// ```
+    pub fn move_to<Q>(&mut self, player: &Q, index: usize) -> Result<(), QueueError>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let pos = self.position(player).ok_or(QueueError::PlayerNotFound)?;
+        let entry = self.queue.remove(pos).expect("position() returned a valid index");
+        let index = index.min(self.queue.len());
+        self.queue.insert(index, entry);
+        Ok(())
+    }
+
+    /// Swaps the positions of two players in the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    ///
+    /// q.swap("player1", "player2").unwrap();
+    /// assert_eq!(q.get_queue(), vec!["player2", "player1"]);
+    /// ```
+    pub fn swap<Q>(&mut self, a: &Q, b: &Q) -> Result<(), QueueError>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let i = self.position(a).ok_or(QueueError::PlayerNotFound)?;
+        let j = self.position(b).ok_or(QueueError::PlayerNotFound)?;
+        self.queue.swap(i, j);
+        Ok(())
+    }
+
+    /// Moves a player to the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    ///
+    /// q.bump_to_front("player2").unwrap();
+    /// assert_eq!(q.get_queue(), vec!["player2", "player1"]);
+    /// ```
+    #[inline]
+    pub fn bump_to_front<Q>(&mut self, player: &Q) -> Result<(), QueueError>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.move_to(player, 0)
+    }
+
+    /// Moves a player to sit immediately before another player in the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    /// q.join("player3");
+    ///
+    /// q.move_before("player3", "player2").unwrap();
+    /// assert_eq!(q.get_queue(), vec!["player1", "player3", "player2"]);
+    /// ```
+    pub fn move_before<Q>(&mut self, player: &Q, target: &Q) -> Result<(), QueueError>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        if player == target {
+            return self.position(player).map(|_| ()).ok_or(QueueError::PlayerNotFound);
+        }
+        let pos = self.position(player).ok_or(QueueError::PlayerNotFound)?;
+        if self.position(target).is_none() {
+            return Err(QueueError::PlayerNotFound);
+        }
+        let entry = self.queue.remove(pos).expect("position() returned a valid index");
+        let target_pos = self
+            .position(target)
+            .expect("target was checked to be in the queue before the removal");
+        self.queue.insert(target_pos, entry);
+        Ok(())
     }
 
     /// Yields the next one player.
     ///
+    /// In priority mode (see [`join_with_priority`](Queue::join_with_priority)), this picks the
+    /// highest-priority player still waiting, breaking ties by join order, which is O(n) rather
+    /// than the O(1) of the plain FIFO case.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -130,12 +509,34 @@ impl<'a> Queue<'a> {
     ///
     /// assert_eq!(q.nextone().unwrap(), "player1");
     /// ```
-    #[inline]
-    pub fn nextone(&mut self) -> Option<&'a str> {
-        self.queue.pop_front()
+    pub fn nextone(&mut self) -> Option<T> {
+        self.next_entry().map(|e| e.player)
+    }
+
+    /// Removes and returns the entry `nextone` would dequeue, priority and all.
+    fn next_entry(&mut self) -> Option<Entry<T>> {
+        if self.priority_mode {
+            let idx = self
+                .queue
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, e)| e.priority_rank())
+                .map(|(idx, _)| idx)?;
+            self.queue.remove(idx)
+        } else {
+            self.queue.pop_front()
+        }
     }
 
-    /// Yields the next one player and push them back to the queue.
+    /// Returns the entries in the order `nextone` would dequeue them, without removing them.
+    fn priority_order(&self) -> Vec<&Entry<T>> {
+        let mut entries: Vec<&Entry<T>> = self.queue.iter().collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.priority_rank()));
+        entries
+    }
+
+    /// Yields the next one player and push them back to the queue, keeping their priority
+    /// (see [`join_with_priority`](Queue::join_with_priority)) for the next rotation.
     ///
     /// # Examples
     ///
@@ -153,10 +554,11 @@ impl<'a> Queue<'a> {
     /// assert_eq!(q.get_queue(), vec!["player2", "player1"]);
     /// ```
     #[inline]
-    pub fn nextone_to_back(&mut self) -> Result<Option<&'a str>, QueueError> {
-        let player = self.nextone();
-        if let Some(p) = player {
-            self.join(p)?;
+    pub fn nextone_to_back(&mut self) -> Result<Option<T>, QueueError> {
+        let entry = self.next_entry();
+        let player = entry.as_ref().map(|e| e.player.clone());
+        if let Some(Entry { priority, player, .. }) = entry {
+            self.join_with_priority(player, priority)?;
         }
         Ok(player)
     }
@@ -177,7 +579,7 @@ impl<'a> Queue<'a> {
     ///
     /// assert_eq!(q.next_group(), vec!["player1", "player2"]);
     /// ```
-    pub fn next_group(&mut self) -> Vec<&'a str> {
+    pub fn next_group(&mut self) -> Vec<T> {
         let mut result = Vec::new();
         for _ in 0..self.players {
             if let Some(p) = self.nextone() {
@@ -204,7 +606,7 @@ impl<'a> Queue<'a> {
     /// assert_eq!(q.next_group_to_back().unwrap(), vec!["player1", "player2"]);
     /// assert_eq!(q.get_queue(), vec!["player3", "player1", "player2"]);
     /// ```
-    pub fn next_group_to_back(&mut self) -> Result<Vec<&'a str>, QueueError> {
+    pub fn next_group_to_back(&mut self) -> Result<Vec<T>, QueueError> {
         let mut result = Vec::new();
         for _ in 0..self.players {
             if let Some(p) = self.nextone_to_back()? {
@@ -231,10 +633,97 @@ impl<'a> Queue<'a> {
     ///
     /// assert_eq!(q.get_queue(), vec!["player1", "player2", "player3"])
     /// ```
-    pub fn get_queue(&self) -> Vec<&'a str> {
-        self.queue.clone().into()
+    pub fn get_queue(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+
+    /// Returns an iterator over the players currently waiting, without cloning the queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    ///
+    /// let mut it = q.iter();
+    /// assert_eq!(it.next(), Some(&"player1"));
+    /// assert_eq!(it.next(), Some(&"player2"));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.queue.iter(),
+        }
     }
 
+    /// Returns the next player without removing them from the queue.
+    ///
+    /// In priority mode this is whoever `nextone` would return, not just the front of the
+    /// deque (see [`join_with_priority`](Queue::join_with_priority)).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 1).unwrap();
+    ///
+    /// q.join("player1");
+    ///
+    /// assert_eq!(q.peek_next(), Some("player1"));
+    /// assert_eq!(q.get_queue(), vec!["player1"]);
+    /// ```
+    pub fn peek_next(&self) -> Option<T> {
+        if self.priority_mode {
+            self.priority_order().into_iter().next().map(|e| e.player.clone())
+        } else {
+            self.queue.front().map(|e| e.player.clone())
+        }
+    }
+
+    /// Returns the next group of players without removing them from the queue.
+    ///
+    /// In priority mode this previews the same order `next_group` would dequeue, not just the
+    /// front of the deque (see [`join_with_priority`](Queue::join_with_priority)).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Queue;
+    ///
+    /// let mut q = Queue::new("", 2).unwrap();
+    ///
+    /// q.join("player1");
+    /// q.join("player2");
+    /// q.join("player3");
+    ///
+    /// assert_eq!(q.peek_group(), vec!["player1", "player2"]);
+    /// assert_eq!(q.get_queue(), vec!["player1", "player2", "player3"]);
+    /// ```
+    pub fn peek_group(&self) -> Vec<T> {
+        if self.priority_mode {
+            self.priority_order()
+                .into_iter()
+                .take(self.players as usize)
+                .map(|e| e.player.clone())
+                .collect()
+        } else {
+            self.iter().take(self.players as usize).cloned().collect()
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + fmt::Display> Queue<T> {
     /// Returns the current formatted queue.
     ///
     /// # Examples
@@ -253,7 +742,7 @@ impl<'a> Queue<'a> {
     /// assert_eq!(q.format_queue(), "player1, player2, player3")
     /// ```
     pub fn format_queue(&self) -> String {
-        self.get_queue().join(", ")
+        self.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", ")
     }
 }
 
@@ -263,8 +752,8 @@ mod queue_tests {
 
     #[test]
     fn test_new() -> Result<(), QueueError> {
-        assert_eq!(Queue::new("", 1)?.get_queue(), Vec::<&str>::new());
-        assert!(Queue::new("", 0).is_err());
+        assert_eq!(Queue::<&str>::new("", 1)?.get_queue(), Vec::<&str>::new());
+        assert!(Queue::<&str>::new("", 0).is_err());
         Ok(())
     }
 
@@ -276,6 +765,52 @@ mod queue_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_join_with_priority() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 1)?;
+        queue.join("player1")?;
+        queue.join_with_priority("player2", 5)?;
+        queue.join_with_priority("player3", 5)?;
+        queue.join("player4")?;
+        assert_eq!(queue.nextone(), Some("player2"));
+        assert_eq!(queue.nextone(), Some("player3"));
+        assert_eq!(queue.nextone(), Some("player1"));
+        assert_eq!(queue.nextone(), Some("player4"));
+        queue.join_with_priority("player1", 1)?;
+        assert!(queue.join_with_priority("player1", 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_capacity() -> Result<(), QueueError> {
+        let queue = Queue::<&str>::with_capacity("test", 1, 2)?;
+        assert_eq!(queue.capacity(), 2);
+        assert!(Queue::<&str>::with_capacity("test", 0, 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_max_len_and_queue_full() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 1)?;
+        queue.set_max_len(Some(1));
+        queue.join("player1")?;
+        assert!(queue.join("player2").is_err());
+        queue.set_max_len(None);
+        queue.join("player2")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_len_and_is_empty() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 1)?;
+        assert_eq!(queue.len(), 0);
+        assert!(queue.is_empty());
+        queue.join("player1")?;
+        assert_eq!(queue.len(), 1);
+        assert!(!queue.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_quit() -> Result<(), QueueError> {
         let mut queue = Queue::new("", 1)?;
@@ -286,6 +821,74 @@ mod queue_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_position() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        assert_eq!(queue.position("player2"), Some(1));
+        assert_eq!(queue.position("player3"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_position_and_quit_by_borrowed_str_on_owned_queue() -> Result<(), QueueError> {
+        let mut queue: Queue<String> = Queue::new("test", 2)?;
+        queue.join("player1".to_string())?;
+        queue.join("player2".to_string())?;
+        assert_eq!(queue.position("player2"), Some(1));
+        queue.quit("player2");
+        assert_eq!(queue.get_queue(), vec!["player1".to_string()]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_to() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        queue.join("player3")?;
+        queue.move_to("player3", 0)?;
+        assert_eq!(queue.get_queue(), vec!["player3", "player1", "player2"]);
+        assert!(queue.move_to("playerx", 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_swap() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        queue.swap("player1", "player2")?;
+        assert_eq!(queue.get_queue(), vec!["player2", "player1"]);
+        assert!(queue.swap("player1", "playerx").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bump_to_front() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        queue.bump_to_front("player2")?;
+        assert_eq!(queue.get_queue(), vec!["player2", "player1"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_before() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        queue.join("player3")?;
+        queue.move_before("player3", "player2")?;
+        assert_eq!(queue.get_queue(), vec!["player1", "player3", "player2"]);
+        queue.move_before("player1", "player3")?;
+        assert_eq!(queue.get_queue(), vec!["player1", "player3", "player2"]);
+        assert!(queue.move_before("player1", "playerx").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_nextone() -> Result<(), QueueError> {
         let mut queue = Queue::new("test", 2)?;
@@ -307,6 +910,16 @@ mod queue_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_nextone_to_back_keeps_priority() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 1)?;
+        queue.join_with_priority("vip", 10)?;
+        queue.join("normal")?;
+        assert_eq!(queue.nextone_to_back()?.unwrap(), "vip");
+        assert_eq!(queue.nextone().unwrap(), "vip");
+        Ok(())
+    }
+
     #[test]
     fn test_next_group() -> Result<(), QueueError> {
         let mut queue = Queue::new("test", 2)?;
@@ -332,6 +945,17 @@ mod queue_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_next_group_to_back_keeps_priority() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join_with_priority("vip1", 10)?;
+        queue.join_with_priority("vip2", 10)?;
+        queue.join("normal")?;
+        assert_eq!(queue.next_group_to_back()?, vec!["vip1", "vip2"]);
+        assert_eq!(queue.next_group_to_back()?, vec!["vip1", "vip2"]);
+        Ok(())
+    }
+
     #[test]
     fn test_get_queue() -> Result<(), QueueError> {
         let mut queue = Queue::new("test", 2)?;
@@ -350,6 +974,62 @@ mod queue_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iter() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec!["player1", "player2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_iter() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        assert_eq!(
+            (&queue).into_iter().copied().collect::<Vec<_>>(),
+            vec!["player1", "player2"]
+        );
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec!["player1", "player2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek_next() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        assert_eq!(queue.peek_next(), Some("player1"));
+        assert_eq!(queue.get_queue(), vec!["player1", "player2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek_group() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("player1")?;
+        queue.join("player2")?;
+        queue.join("player3")?;
+        assert_eq!(queue.peek_group(), vec!["player1", "player2"]);
+        assert_eq!(queue.get_queue(), vec!["player1", "player2", "player3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_peek_matches_next_in_priority_mode() -> Result<(), QueueError> {
+        let mut queue = Queue::new("test", 2)?;
+        queue.join("a")?;
+        queue.join_with_priority("b", 5)?;
+        queue.join_with_priority("c", 5)?;
+        queue.join("d")?;
+        assert_eq!(queue.peek_next(), Some("b"));
+        assert_eq!(queue.peek_group(), vec!["b", "c"]);
+        assert_eq!(queue.next_group(), vec!["b", "c"]);
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), QueueError> {
         let mut queue = Queue::new("test", 2)?;
@@ -0,0 +1,309 @@
+//! A venue-wide manager coordinating several named [`Queue`]s.
+
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use crate::{Queue, QueueError};
+
+#[derive(Error, Debug)]
+pub enum LobbyError {
+    #[error("no game named `{0}`")]
+    GameNotFound(String),
+    #[error("a game named `{0}` already exists")]
+    GameAlreadyExists(String),
+    #[error("the player is banned from this lobby")]
+    PlayerBanned,
+    #[error(transparent)]
+    Queue(#[from] QueueError),
+}
+
+/// A collection of named [`Queue`]s, one per game, with a shared ban list.
+///
+/// There is no separate host/master role: a `Lobby` only coordinates queues and bans, and
+/// anyone able to call its methods can administer it, so room ownership is left to the caller.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use arcade_queue::Lobby;
+///
+/// let mut lobby = Lobby::new();
+///
+/// lobby.create_game("pinball", 1).unwrap();
+/// lobby.join("pinball", "player1").unwrap();
+/// ```
+#[derive(Default)]
+pub struct Lobby<'a> {
+    games: HashMap<&'a str, Queue<&'a str>>,
+    banned: HashSet<&'a str>,
+}
+
+impl<'a> Lobby<'a> {
+    /// Creates an empty lobby with no games and no bans.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let lobby = Lobby::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            games: HashMap::new(),
+            banned: HashSet::new(),
+        }
+    }
+
+    /// Adds a new game to the lobby with its own queue. The game should not already exist.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// ```
+    pub fn create_game(&mut self, name: &'a str, players_per_round: u8) -> Result<(), LobbyError> {
+        if self.games.contains_key(name) {
+            return Err(LobbyError::GameAlreadyExists(name.to_string()));
+        }
+        let queue = Queue::new(name, players_per_round)?;
+        self.games.insert(name, queue);
+        Ok(())
+    }
+
+    /// Removes a game and its queue from the lobby.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.remove_game("pinball").unwrap();
+    /// ```
+    pub fn remove_game(&mut self, name: &str) -> Result<(), LobbyError> {
+        self.games
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| LobbyError::GameNotFound(name.to_string()))
+    }
+
+    /// Adds a player to a game's queue. Fails if the game does not exist or the player is
+    /// banned from the lobby.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.join("pinball", "player1").unwrap();
+    /// ```
+    pub fn join(&mut self, game: &str, player: &'a str) -> Result<(), LobbyError> {
+        if self.banned.contains(player) {
+            return Err(LobbyError::PlayerBanned);
+        }
+        let queue = self
+            .games
+            .get_mut(game)
+            .ok_or_else(|| LobbyError::GameNotFound(game.to_string()))?;
+        queue.join(player)?;
+        Ok(())
+    }
+
+    /// Removes a player from every game's queue.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.join("pinball", "player1").unwrap();
+    /// lobby.quit_all("player1");
+    /// ```
+    pub fn quit_all(&mut self, player: &str) {
+        for queue in self.games.values_mut() {
+            queue.quit(player);
+        }
+    }
+
+    /// Lists the games a player is currently waiting for.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.join("pinball", "player1").unwrap();
+    ///
+    /// assert_eq!(lobby.whereis("player1"), vec!["pinball"]);
+    /// ```
+    pub fn whereis(&self, player: &str) -> Vec<&'a str> {
+        self.games
+            .iter()
+            .filter(|(_, queue)| queue.position(player).is_some())
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// Bans a player: removes them from every game's queue right away (like
+    /// [`quit_all`](Lobby::quit_all)) and causes future [`join`](Lobby::join) calls for them to
+    /// fail.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.join("pinball", "player1").unwrap();
+    /// lobby.ban("player1");
+    ///
+    /// assert_eq!(lobby.whereis("player1"), Vec::<&str>::new());
+    /// assert!(lobby.join("pinball", "player1").is_err());
+    /// ```
+    pub fn ban(&mut self, player: &'a str) {
+        self.banned.insert(player);
+        self.quit_all(player);
+    }
+
+    /// Lifts a ban on a player.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arcade_queue::Lobby;
+    ///
+    /// let mut lobby = Lobby::new();
+    ///
+    /// lobby.create_game("pinball", 1).unwrap();
+    /// lobby.ban("player1");
+    /// lobby.unban("player1");
+    ///
+    /// assert!(lobby.join("pinball", "player1").is_ok());
+    /// ```
+    pub fn unban(&mut self, player: &str) {
+        self.banned.remove(player);
+    }
+}
+
+#[cfg(test)]
+mod lobby_tests {
+    use crate::*;
+
+    #[test]
+    fn test_create_game() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        assert!(lobby.create_game("pinball", 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_game() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.remove_game("pinball")?;
+        assert!(lobby.remove_game("pinball").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_join() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.join("pinball", "player1")?;
+        assert!(lobby.join("arcade", "player1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_quit_all() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.create_game("arcade", 1)?;
+        lobby.join("pinball", "player1")?;
+        lobby.join("arcade", "player1")?;
+        lobby.quit_all("player1");
+        assert_eq!(lobby.whereis("player1"), Vec::<&str>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_whereis() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.join("pinball", "player1")?;
+        assert_eq!(lobby.whereis("player1"), vec!["pinball"]);
+        assert_eq!(lobby.whereis("player2"), Vec::<&str>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_whereis_accepts_a_short_lived_borrow() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.join("pinball", "player1")?;
+        let query = String::from("player1");
+        assert_eq!(lobby.whereis(&query), vec!["pinball"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ban_unban() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.ban("player1");
+        assert!(lobby.join("pinball", "player1").is_err());
+        lobby.unban("player1");
+        lobby.join("pinball", "player1")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_ban_evicts_from_queues() -> Result<(), LobbyError> {
+        let mut lobby = Lobby::new();
+        lobby.create_game("pinball", 1)?;
+        lobby.create_game("arcade", 1)?;
+        lobby.join("pinball", "player1")?;
+        lobby.join("arcade", "player1")?;
+        lobby.ban("player1");
+        assert_eq!(lobby.whereis("player1"), Vec::<&str>::new());
+        Ok(())
+    }
+}